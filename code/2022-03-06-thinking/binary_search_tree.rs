@@ -0,0 +1,280 @@
+//! A dynamic binary search tree, for repeated membership queries on a data set that changes
+//! between searches (the flat, array-based `search` API re-sorts and re-scans on every call,
+//! which is wasteful once the data starts mutating).
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BinarySearchTree<T: Ord> {
+    root: Option<Box<Node<T>>>,
+    size: usize,
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    pub fn new() -> Self {
+        BinarySearchTree {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Inserts `value` into the tree. Returns `false` if an equal value was already present,
+    /// in which case the tree is left unchanged.
+    pub fn insert(&mut self, value: T) -> bool {
+        let inserted = Self::insert_node(&mut self.root, value);
+        if inserted {
+            self.size += 1;
+        }
+        inserted
+    }
+
+    fn insert_node(node: &mut Option<Box<Node<T>>>, value: T) -> bool {
+        match node {
+            None => {
+                *node = Some(Box::new(Node {
+                    value,
+                    left: None,
+                    right: None,
+                }));
+                true
+            }
+            Some(node) => {
+                if value == node.value {
+                    false
+                } else if value < node.value {
+                    Self::insert_node(&mut node.left, value)
+                } else {
+                    Self::insert_node(&mut node.right, value)
+                }
+            }
+        }
+    }
+
+    /// Returns whether `value` is present in the tree.
+    pub fn contains(&self, value: &T) -> bool {
+        Self::contains_node(&self.root, value)
+    }
+
+    fn contains_node(node: &Option<Box<Node<T>>>, value: &T) -> bool {
+        match node {
+            None => false,
+            Some(node) => {
+                if *value == node.value {
+                    true
+                } else if *value < node.value {
+                    Self::contains_node(&node.left, value)
+                } else {
+                    Self::contains_node(&node.right, value)
+                }
+            }
+        }
+    }
+
+    /// Removes `value` from the tree, if present, splicing in the in-order successor when the
+    /// removed node has two children. Returns whether `value` was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let (new_root, removed) = Self::remove_node(self.root.take(), value);
+        self.root = new_root;
+        if removed {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    fn remove_node(node: Option<Box<Node<T>>>, value: &T) -> (Option<Box<Node<T>>>, bool) {
+        let mut node = match node {
+            None => return (None, false),
+            Some(node) => node,
+        };
+
+        if *value < node.value {
+            let (new_left, removed) = Self::remove_node(node.left.take(), value);
+            node.left = new_left;
+            (Some(node), removed)
+        } else if *value > node.value {
+            let (new_right, removed) = Self::remove_node(node.right.take(), value);
+            node.right = new_right;
+            (Some(node), removed)
+        } else {
+            match (node.left.take(), node.right.take()) {
+                (None, None) => (None, true),
+                (Some(left), None) => (Some(left), true),
+                (None, Some(right)) => (Some(right), true),
+                (Some(left), Some(right)) => {
+                    let (new_right, successor) = Self::take_min(*right);
+                    node.value = successor;
+                    node.left = Some(left);
+                    node.right = new_right;
+                    (Some(node), true)
+                }
+            }
+        }
+    }
+
+    /// Splits off the minimum (leftmost) value of `node`, returning the remaining subtree
+    /// alongside it.
+    fn take_min(node: Node<T>) -> (Option<Box<Node<T>>>, T) {
+        let Node { value, left, right } = node;
+
+        match left {
+            None => (right, value),
+            Some(left) => {
+                let (new_left, min_value) = Self::take_min(*left);
+                (
+                    Some(Box::new(Node {
+                        value,
+                        left: new_left,
+                        right,
+                    })),
+                    min_value,
+                )
+            }
+        }
+    }
+
+    /// Returns an iterator over the elements of the tree, in sorted order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    fn collect_in_order(node: Option<Box<Node<T>>>, items: &mut Vec<T>) {
+        if let Some(node) = node {
+            let Node { value, left, right } = *node;
+            Self::collect_in_order(left, items);
+            items.push(value);
+            Self::collect_in_order(right, items);
+        }
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinarySearchTree<T> {
+    fn from(list: Vec<T>) -> Self {
+        let mut tree = BinarySearchTree::new();
+        for value in list {
+            tree.insert(value);
+        }
+        tree
+    }
+}
+
+/// Borrowing, in-order iterator over a [`BinarySearchTree`], yielding elements in sorted
+/// order.
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+
+/// Owning, in-order iterator over a [`BinarySearchTree`], yielding elements in sorted order.
+pub type IntoIter<T> = std::vec::IntoIter<T>;
+
+impl<T: Ord> IntoIterator for BinarySearchTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut items = Vec::with_capacity(self.size);
+        Self::collect_in_order(self.root, &mut items);
+        items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Some tests to verify our implementation.
+
+    use super::*;
+
+    #[test]
+    fn test_insert_contains() {
+        let mut tree = BinarySearchTree::new();
+
+        assert!(tree.insert(5));
+        assert!(tree.insert(3));
+        assert!(tree.insert(8));
+        assert!(!tree.insert(5));
+        assert_eq!(tree.size, 3);
+
+        assert!(tree.contains(&5));
+        assert!(tree.contains(&3));
+        assert!(tree.contains(&8));
+        assert!(!tree.contains(&9));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree = BinarySearchTree::new();
+        for value in [5, 3, 8, 1, 4, 7, 9] {
+            tree.insert(value);
+        }
+
+        assert!(tree.remove(&3));
+        assert!(!tree.contains(&3));
+        assert!(!tree.remove(&3));
+
+        // removing a node with two children splices in its in-order successor
+        assert!(tree.remove(&5));
+        assert!(!tree.contains(&5));
+        assert_eq!(
+            tree.iter().cloned().collect::<Vec<_>>(),
+            vec![1, 4, 7, 8, 9]
+        );
+        assert_eq!(tree.size, 5);
+    }
+
+    #[test]
+    fn test_iter_in_order() {
+        let mut tree = BinarySearchTree::new();
+        for value in [5, 3, 8, 1, 4, 7, 9] {
+            tree.insert(value);
+        }
+
+        assert_eq!(
+            tree.iter().cloned().collect::<Vec<_>>(),
+            vec![1, 3, 4, 5, 7, 8, 9]
+        );
+        assert_eq!(
+            tree.into_iter().collect::<Vec<_>>(),
+            vec![1, 3, 4, 5, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let tree = BinarySearchTree::from(vec![5, 3, 8, 1, 4, 7, 9]);
+        assert_eq!(
+            tree.iter().cloned().collect::<Vec<_>>(),
+            vec![1, 3, 4, 5, 7, 8, 9]
+        );
+    }
+}
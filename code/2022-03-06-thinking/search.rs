@@ -14,10 +14,14 @@ where
     is_sorted: bool,
 }
 
-impl<T: Searchable> SearchList<'_, T>
+impl<'a, T: Searchable> SearchList<'a, T>
 where
     [T]: ToOwned<Owned = Vec<T>>,
 {
+    pub fn new(list: Cow<'a, [T]>, is_sorted: bool) -> Self {
+        SearchList { list, is_sorted }
+    }
+
     fn sort(&mut self) {
         if !self.is_sorted {
             self.list.to_mut().sort();
@@ -31,50 +35,292 @@ pub struct SearchResult {
     index: Option<usize>,
 }
 
-/// Recursive implementation of binary search algorithm.
-fn binary_search<T: Searchable>(search_list: SearchList<T>, item: T) -> SearchResult
+/// Recursive implementation of binary search algorithm, returning the matched index on a
+/// hit or the sorted insertion point on a miss.
+fn binary_search<T, Q>(search_list: SearchList<T>, item: &Q) -> SearchResult
+where
+    T: Searchable,
+    [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    binary_search_from(search_list, item, 0)
+}
+
+/// `binary_search`, tracking the `offset` of `search_list` within the original list.
+fn binary_search_from<T, Q>(search_list: SearchList<T>, item: &Q, offset: usize) -> SearchResult
 where
+    T: Searchable,
     [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
 {
     let l = search_list.list.len();
 
     if l == 0 {
         SearchResult {
             found: false,
-            index: None,
+            index: Some(offset),
         }
     } else {
         let mid = l / 2_usize;
 
-        if item == search_list.list[mid] {
+        if item == search_list.list[mid].borrow() {
             SearchResult {
                 found: true,
-                index: None,
+                index: Some(offset + mid),
             }
-        } else if item < search_list.list[mid] {
-            binary_search(
+        } else if item < search_list.list[mid].borrow() {
+            binary_search_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[..mid]),
+                    ..search_list
+                },
+                item,
+                offset,
+            )
+        } else {
+            binary_search_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[mid + 1..]),
+                    ..search_list
+                },
+                item,
+                offset + mid + 1,
+            )
+        }
+    }
+}
+
+/// Biased binary search returning the lowest index of an element equal to `item`.
+fn binary_search_first<T, Q>(search_list: SearchList<T>, item: &Q) -> SearchResult
+where
+    T: Searchable,
+    [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    binary_search_first_from(search_list, item, 0, None)
+}
+
+/// `binary_search_first`, tracking the `offset` of `search_list` and the lowest matching
+/// index found so far (`candidate`).
+fn binary_search_first_from<T, Q>(
+    search_list: SearchList<T>,
+    item: &Q,
+    offset: usize,
+    candidate: Option<usize>,
+) -> SearchResult
+where
+    T: Searchable,
+    [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let l = search_list.list.len();
+
+    if l == 0 {
+        SearchResult {
+            found: candidate.is_some(),
+            index: candidate.or(Some(offset)),
+        }
+    } else {
+        let mid = l / 2_usize;
+
+        if item == search_list.list[mid].borrow() {
+            binary_search_first_from(
                 SearchList {
                     list: Cow::Borrowed(&search_list.list[..mid]),
                     ..search_list
                 },
                 item,
+                offset,
+                Some(offset + mid),
+            )
+        } else if item < search_list.list[mid].borrow() {
+            binary_search_first_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[..mid]),
+                    ..search_list
+                },
+                item,
+                offset,
+                candidate,
             )
         } else {
-            binary_search(
+            binary_search_first_from(
                 SearchList {
                     list: Cow::Borrowed(&search_list.list[mid + 1..]),
                     ..search_list
                 },
                 item,
+                offset + mid + 1,
+                candidate,
             )
         }
     }
 }
 
+/// Biased binary search returning the highest index of an element equal to `item`.
+fn binary_search_last<T, Q>(search_list: SearchList<T>, item: &Q) -> SearchResult
+where
+    T: Searchable,
+    [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    binary_search_last_from(search_list, item, 0, None)
+}
+
+/// `binary_search_last`, tracking the `offset` of `search_list` and the highest matching
+/// index found so far (`candidate`).
+fn binary_search_last_from<T, Q>(
+    search_list: SearchList<T>,
+    item: &Q,
+    offset: usize,
+    candidate: Option<usize>,
+) -> SearchResult
+where
+    T: Searchable,
+    [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let l = search_list.list.len();
+
+    if l == 0 {
+        SearchResult {
+            found: candidate.is_some(),
+            index: candidate.or(Some(offset)),
+        }
+    } else {
+        let mid = l / 2_usize;
+
+        if item == search_list.list[mid].borrow() {
+            binary_search_last_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[mid + 1..]),
+                    ..search_list
+                },
+                item,
+                offset + mid + 1,
+                Some(offset + mid),
+            )
+        } else if item < search_list.list[mid].borrow() {
+            binary_search_last_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[..mid]),
+                    ..search_list
+                },
+                item,
+                offset,
+                candidate,
+            )
+        } else {
+            binary_search_last_from(
+                SearchList {
+                    list: Cow::Borrowed(&search_list.list[mid + 1..]),
+                    ..search_list
+                },
+                item,
+                offset + mid + 1,
+                candidate,
+            )
+        }
+    }
+}
+
+/// Iterative, branchless implementation of binary search algorithm.
+fn binary_search_iterative<T: Searchable>(search_list: SearchList<T>, item: T) -> SearchResult
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    let list = &search_list.list;
+
+    if list.is_empty() {
+        return SearchResult {
+            found: false,
+            index: Some(0),
+        };
+    }
+
+    let mut base = 0_usize;
+    let mut size = list.len();
+
+    while size > 1 {
+        let half = size / 2;
+        let mid = base + half;
+        base = if list[mid] <= item { mid } else { base };
+        size -= half;
+    }
+
+    if list[base] == item {
+        SearchResult {
+            found: true,
+            index: Some(base),
+        }
+    } else if list[base] < item {
+        SearchResult {
+            found: false,
+            index: Some(base + 1),
+        }
+    } else {
+        SearchResult {
+            found: false,
+            index: Some(base),
+        }
+    }
+}
+
+/// Comparator-based sibling of `binary_search`, which does not require `T: Ord`. Takes a
+/// raw `&[T]` rather than a `SearchList<T>` since there's no `Searchable` bound to plumb.
+fn binary_search_by<T>(list: &[T], mut f: impl FnMut(&T) -> std::cmp::Ordering) -> SearchResult {
+    binary_search_by_from(list, &mut f, 0)
+}
+
+/// `binary_search_by`, tracking the `offset` of `list` within the original list.
+fn binary_search_by_from<T>(
+    list: &[T],
+    f: &mut impl FnMut(&T) -> std::cmp::Ordering,
+    offset: usize,
+) -> SearchResult {
+    use std::cmp::Ordering;
+
+    if list.is_empty() {
+        SearchResult {
+            found: false,
+            index: Some(offset),
+        }
+    } else {
+        let mid = list.len() / 2_usize;
+
+        match f(&list[mid]) {
+            Ordering::Equal => SearchResult {
+                found: true,
+                index: Some(offset + mid),
+            },
+            Ordering::Less => binary_search_by_from(&list[mid + 1..], f, offset + mid + 1),
+            Ordering::Greater => binary_search_by_from(&list[..mid], f, offset),
+        }
+    }
+}
+
+/// Key-based sibling of `binary_search`, searching `list` by key rather than by element.
+fn binary_search_by_key<T, K: Ord>(
+    list: &[T],
+    key: &K,
+    mut f: impl FnMut(&T) -> K,
+) -> SearchResult {
+    binary_search_by(list, |elem| f(elem).cmp(key))
+}
+
 /// Recursive implementation of linear search algorithm.
-fn linear_search<T: Searchable>(search_list: SearchList<T>, item: T) -> SearchResult
+fn linear_search<T, Q>(search_list: SearchList<T>, item: &Q) -> SearchResult
 where
+    T: Searchable,
     [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
 {
     if search_list.list.len() == 0 {
         SearchResult {
@@ -82,12 +328,12 @@ where
             index: None,
         }
     } else {
-        if item == search_list.list[0] {
+        if item == search_list.list[0].borrow() {
             SearchResult {
                 found: true,
                 index: Some(0),
             }
-        } else if search_list.is_sorted && item < search_list.list[0] {
+        } else if search_list.is_sorted && item < search_list.list[0].borrow() {
             SearchResult {
                 found: false,
                 index: None,
@@ -113,16 +359,17 @@ where
 pub enum SearchKind {
     CheckPresence,
     FindIndex,
+    FindFirst,
+    FindLast,
 }
 
 /// User-facing function to search for an item in a list.
-pub fn search<T: Searchable>(
-    mut search_list: SearchList<T>,
-    item: T,
-    kind: SearchKind,
-) -> SearchResult
+pub fn search<T, Q>(mut search_list: SearchList<T>, item: &Q, kind: SearchKind) -> SearchResult
 where
+    T: Searchable,
     [T]: ToOwned<Owned = Vec<T>>,
+    T: std::borrow::Borrow<Q>,
+    Q: Ord + ?Sized,
 {
     match kind {
         SearchKind::CheckPresence => {
@@ -130,6 +377,69 @@ where
             binary_search(search_list, item)
         }
         SearchKind::FindIndex => linear_search(search_list, item),
+        SearchKind::FindFirst => {
+            search_list.sort();
+            binary_search_first(search_list, item)
+        }
+        SearchKind::FindLast => {
+            search_list.sort();
+            binary_search_last(search_list, item)
+        }
+    }
+}
+
+/// Extension trait exposing the search algorithms directly on a slice (or `Vec`), so callers
+/// don't need to wrap their data in a `SearchList` for a one-shot search.
+pub trait ImmutableSearchSlice<T: Searchable>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    fn linear_search<Q>(&self, item: &Q) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Named `search_binary` rather than `binary_search` so it isn't shadowed by the
+    /// inherent `[T]::binary_search` from the standard library.
+    fn search_binary<Q>(&self, item: &Q) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    fn search<Q>(&self, item: &Q, kind: SearchKind) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized;
+}
+
+impl<T: Searchable> ImmutableSearchSlice<T> for [T]
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    fn linear_search<Q>(&self, item: &Q) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        linear_search(SearchList::new(Cow::Borrowed(self), false), item)
+    }
+
+    fn search_binary<Q>(&self, item: &Q) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut search_list = SearchList::new(Cow::Borrowed(self), false);
+        search_list.sort();
+        binary_search(search_list, item)
+    }
+
+    fn search<Q>(&self, item: &Q, kind: SearchKind) -> SearchResult
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        search(SearchList::new(Cow::Borrowed(self), false), item, kind)
     }
 }
 
@@ -146,15 +456,15 @@ mod tests {
             is_sorted: true,
         };
 
-        assert!(linear_search(search_list.clone(), 5).found);
-        assert_eq!(linear_search(search_list.clone(), 5).index, Some(5));
-        assert!(linear_search(search_list.clone(), 0).found);
-        assert_eq!(linear_search(search_list.clone(), 0).index, Some(0));
-        assert!(linear_search(search_list.clone(), 9).found);
-        assert_eq!(linear_search(search_list.clone(), 9).index, Some(9));
+        assert!(linear_search(search_list.clone(), &5).found);
+        assert_eq!(linear_search(search_list.clone(), &5).index, Some(5));
+        assert!(linear_search(search_list.clone(), &0).found);
+        assert_eq!(linear_search(search_list.clone(), &0).index, Some(0));
+        assert!(linear_search(search_list.clone(), &9).found);
+        assert_eq!(linear_search(search_list.clone(), &9).index, Some(9));
 
-        assert!(!linear_search(search_list.clone(), 15).found);
-        assert_eq!(linear_search(search_list.clone(), 15).index, None);
+        assert!(!linear_search(search_list.clone(), &15).found);
+        assert_eq!(linear_search(search_list.clone(), &15).index, None);
     }
 
     #[test]
@@ -164,15 +474,97 @@ mod tests {
             is_sorted: true,
         };
 
-        assert!(binary_search(search_list.clone(), 5).found);
-        assert_eq!(binary_search(search_list.clone(), 5).index, None);
-        assert!(binary_search(search_list.clone(), 0).found);
-        assert_eq!(binary_search(search_list.clone(), 0).index, None);
-        assert!(binary_search(search_list.clone(), 9).found);
-        assert_eq!(binary_search(search_list.clone(), 9).index, None);
+        assert!(binary_search(search_list.clone(), &5).found);
+        assert_eq!(binary_search(search_list.clone(), &5).index, Some(5));
+        assert!(binary_search(search_list.clone(), &0).found);
+        assert_eq!(binary_search(search_list.clone(), &0).index, Some(0));
+        assert!(binary_search(search_list.clone(), &9).found);
+        assert_eq!(binary_search(search_list.clone(), &9).index, Some(9));
 
-        assert!(!binary_search(search_list.clone(), 15).found);
-        assert_eq!(binary_search(search_list.clone(), 15).index, None);
+        assert!(!binary_search(search_list.clone(), &15).found);
+        assert_eq!(binary_search(search_list.clone(), &15).index, Some(10));
+    }
+
+    #[test]
+    fn test_binary_search_first_and_last() {
+        let search_list = SearchList {
+            list: Cow::Borrowed(&[0, 1, 1, 1, 2, 3, 3, 4, 5]),
+            is_sorted: true,
+        };
+
+        assert!(binary_search_first(search_list.clone(), &1).found);
+        assert_eq!(binary_search_first(search_list.clone(), &1).index, Some(1));
+        assert!(binary_search_last(search_list.clone(), &1).found);
+        assert_eq!(binary_search_last(search_list.clone(), &1).index, Some(3));
+
+        assert!(binary_search_first(search_list.clone(), &3).found);
+        assert_eq!(binary_search_first(search_list.clone(), &3).index, Some(5));
+        assert!(binary_search_last(search_list.clone(), &3).found);
+        assert_eq!(binary_search_last(search_list.clone(), &3).index, Some(6));
+
+        assert!(!binary_search_first(search_list.clone(), &9).found);
+        assert!(!binary_search_last(search_list.clone(), &9).found);
+    }
+
+    #[test]
+    fn test_binary_search_iterative() {
+        let search_list = SearchList {
+            list: Cow::Borrowed(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            is_sorted: true,
+        };
+
+        assert!(binary_search_iterative(search_list.clone(), 5).found);
+        assert_eq!(
+            binary_search_iterative(search_list.clone(), 5).index,
+            Some(5)
+        );
+        assert!(binary_search_iterative(search_list.clone(), 0).found);
+        assert_eq!(
+            binary_search_iterative(search_list.clone(), 0).index,
+            Some(0)
+        );
+        assert!(binary_search_iterative(search_list.clone(), 9).found);
+        assert_eq!(
+            binary_search_iterative(search_list.clone(), 9).index,
+            Some(9)
+        );
+
+        assert!(!binary_search_iterative(search_list.clone(), 15).found);
+        assert_eq!(
+            binary_search_iterative(search_list.clone(), 15).index,
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_binary_search_by() {
+        let list = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        assert!(binary_search_by(&list, |elem| elem.cmp(&5)).found);
+        assert_eq!(binary_search_by(&list, |elem| elem.cmp(&5)).index, Some(5));
+
+        assert!(!binary_search_by(&list, |elem| elem.cmp(&15)).found);
+        assert_eq!(
+            binary_search_by(&list, |elem| elem.cmp(&15)).index,
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_binary_search_by_key() {
+        let records = [(0, "a"), (1, "b"), (2, "c"), (3, "d"), (4, "e")];
+
+        assert!(binary_search_by_key(&records, &2, |elem| elem.0).found);
+        assert_eq!(
+            binary_search_by_key(&records, &2, |elem| elem.0).index,
+            Some(2)
+        );
+
+        assert!(!binary_search_by_key(&records, &9, |elem| elem.0).found);
+        assert_eq!(
+            binary_search_by_key(&records, &9, |elem| elem.0).index,
+            Some(5)
+        );
     }
 
     #[test]
@@ -186,15 +578,42 @@ mod tests {
             is_sorted: false,
         };
 
-        assert!(search(search_list_sorted.clone(), 5, SearchKind::CheckPresence).found);
-        assert!(!search(search_list_unsorted.clone(), 15, SearchKind::CheckPresence).found);
+        assert!(search(search_list_sorted.clone(), &5, SearchKind::CheckPresence).found);
+        assert!(!search(search_list_unsorted.clone(), &15, SearchKind::CheckPresence).found);
         assert_eq!(
-            search(search_list_sorted.clone(), 5, SearchKind::FindIndex).index,
+            search(search_list_sorted.clone(), &5, SearchKind::FindIndex).index,
             Some(5)
         );
         assert_eq!(
-            search(search_list_unsorted.clone(), 15, SearchKind::FindIndex).index,
+            search(search_list_unsorted.clone(), &15, SearchKind::FindIndex).index,
             None
         );
     }
+
+    #[test]
+    fn test_search_borrowed_query() {
+        let list = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let search_list = SearchList {
+            list: Cow::Borrowed(&list[..]),
+            is_sorted: true,
+        };
+
+        assert!(linear_search(search_list.clone(), "b").found);
+        assert!(binary_search(search_list.clone(), "b").found);
+        assert!(search(search_list.clone(), "b", SearchKind::CheckPresence).found);
+    }
+
+    #[test]
+    fn test_immutable_search_slice() {
+        let list = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        assert!(list.linear_search(&5).found);
+        assert_eq!(list.linear_search(&5).index, Some(5));
+
+        assert!(list.search_binary(&5).found);
+        assert_eq!(list.search_binary(&5).index, Some(5));
+
+        assert!(list.search(&5, SearchKind::CheckPresence).found);
+        assert!(!list.search(&15, SearchKind::CheckPresence).found);
+    }
 }